@@ -4358,6 +4358,7 @@ impl Editor {
                         task_source_kind,
                         resolved_task,
                         false,
+                        window,
                         cx,
                     );
 
@@ -5324,12 +5325,13 @@ impl Editor {
             resolved.reveal = reveal_strategy;
 
             workspace
-                .update(&mut cx, |workspace, cx| {
+                .update_in(&mut cx, |workspace, window, cx| {
                     workspace::tasks::schedule_resolved_task(
                         workspace,
                         task_source_kind,
                         resolved_task,
                         false,
+                        window,
                         cx,
                     );
                 })
@@ -84,7 +84,18 @@ pub fn task_context(
         .active_item(cx)
         .and_then(|item| item.act_as::<Editor>(cx))
     else {
-        return AsyncTask::ready(TaskContext::default());
+        let host_os = workspace
+            .project()
+            .read(cx)
+            .task_store()
+            .clone()
+            .update(cx, |task_store, cx| task_store.host_os(cx));
+        return cx.background_executor().spawn(async move {
+            TaskContext {
+                os: host_os.await,
+                ..TaskContext::default()
+            }
+        });
     };
     editor.update(cx, |editor, cx| {
         let context_task = task_context_with_editor(editor, window, cx);
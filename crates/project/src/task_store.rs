@@ -60,10 +60,13 @@ impl TaskStore {
         envelope: TypedEnvelope<proto::TaskContextForLocation>,
         mut cx: AsyncApp,
     ) -> anyhow::Result<proto::TaskContext> {
-        let location = envelope
-            .payload
-            .location
-            .context("no location given for task context handling")?;
+        // Requests without a location only ask for the host's OS.
+        let Some(location) = envelope.payload.location else {
+            return Ok(proto::TaskContext {
+                os: Some(std::env::consts::OS.to_string()),
+                ..proto::TaskContext::default()
+            });
+        };
         let (buffer_store, is_remote) = store.update(&mut cx, |store, _| {
             Ok(match store {
                 TaskStore::Functional(state) => (
@@ -148,6 +151,7 @@ impl TaskStore {
                 .into_iter()
                 .map(|(variable_name, variable_value)| (variable_name.to_string(), variable_value))
                 .collect(),
+            os: task_context.os,
         })
     }
 
@@ -227,6 +231,27 @@ impl TaskStore {
         }
     }
 
+    /// Returns the OS of the machine the project's tasks run on.
+    pub fn host_os(&self, cx: &mut App) -> Task<Option<String>> {
+        match self {
+            TaskStore::Functional(state) => match &state.mode {
+                StoreMode::Local { .. } => Task::ready(Some(std::env::consts::OS.to_string())),
+                StoreMode::Remote {
+                    upstream_client,
+                    project_id,
+                } => {
+                    let context_task = upstream_client.request(proto::TaskContextForLocation {
+                        project_id: *project_id,
+                        location: None,
+                        task_variables: Default::default(),
+                    });
+                    cx.spawn(|_| async move { context_task.await.log_err()?.os })
+                }
+            },
+            TaskStore::Noop => Task::ready(None),
+        }
+    }
+
     pub fn task_inventory(&self) -> Option<&Entity<Inventory>> {
         match self {
             TaskStore::Functional(state) => Some(&state.task_inventory),
@@ -356,6 +381,7 @@ fn local_task_context_for_location(
             project_env: project_env.unwrap_or_default(),
             cwd: worktree_abs_path.map(|p| p.to_path_buf()),
             task_variables,
+            os: Some(std::env::consts::OS.to_string()),
         })
     })
 }
@@ -419,6 +445,7 @@ fn remote_task_context_for_location(
                 )
                 .collect(),
             project_env: task_context.project_env.into_iter().collect(),
+            os: task_context.os,
         })
     })
 }
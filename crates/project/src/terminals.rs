@@ -273,6 +273,8 @@ impl Project {
                     status: TaskStatus::Running,
                     show_summary: spawn_task.show_summary,
                     show_command: spawn_task.show_command,
+                    interrupted: false,
+                    retries_left: spawn_task.retry.map_or(0, |retry| retry.attempts),
                     completion_rx,
                 });
 
@@ -13,7 +13,9 @@ use std::borrow::Cow;
 use std::path::PathBuf;
 use std::str::FromStr;
 
-pub use task_template::{HideStrategy, RevealStrategy, TaskTemplate, TaskTemplates};
+pub use task_template::{
+    HideStrategy, RevealStrategy, TaskOsOverrides, TaskRetry, TaskTemplate, TaskTemplates,
+};
 pub use vscode_format::VsCodeTaskFile;
 pub use zed_actions::RevealTarget;
 
@@ -58,6 +60,10 @@ pub struct SpawnInTerminal {
     pub show_summary: bool,
     /// Whether to show the command line in the task output.
     pub show_command: bool,
+    /// Whether and how to rerun the task if it finishes with a non-zero exit code.
+    pub retry: Option<TaskRetry>,
+    /// Whether to ask for a confirmation before spawning the task.
+    pub confirm: bool,
 }
 
 /// A final form of the [`TaskTemplate`], that got resolved with a particular [`TaskContext`] and now is ready to spawn the actual task.
@@ -259,6 +265,10 @@ pub struct TaskContext {
     /// This is the environment one would get when `cd`ing in a terminal
     /// into the project's root directory.
     pub project_env: HashMap<String, String>,
+    /// The OS the task is going to run on, as in [`std::env::consts::OS`].
+    /// For remote projects, this is the OS of the remote host.
+    /// If not set, the OS Zed runs on is assumed.
+    pub os: Option<String>,
 }
 
 /// This is a new type representing a 'tag' on a 'runnable symbol', typically a test of main() function, found via treesitter.
@@ -298,6 +308,7 @@ enum WindowsShellType {
 pub struct ShellBuilder {
     program: String,
     args: Vec<String>,
+    interactive: bool,
 }
 
 impl ShellBuilder {
@@ -314,7 +325,25 @@ impl ShellBuilder {
             Shell::Program(shell) => (shell.clone(), Vec::new()),
             Shell::WithArguments { program, args, .. } => (program.clone(), args.clone()),
         };
-        Self { program, args }
+        Self {
+            program,
+            args,
+            interactive: true,
+        }
+    }
+
+    /// Runs the command in a non-interactive shell, for commands that have no terminal attached.
+    pub fn non_interactive(mut self) -> Self {
+        self.interactive = false;
+        self
+    }
+
+    fn interactive_flag(&self) -> &'static str {
+        if self.interactive {
+            "-i "
+        } else {
+            ""
+        }
     }
 }
 
@@ -322,7 +351,12 @@ impl ShellBuilder {
 impl ShellBuilder {
     /// Returns the label to show in the terminal tab
     pub fn command_label(&self, command_label: &str) -> String {
-        format!("{} -i -c '{}'", self.program, command_label)
+        format!(
+            "{} {}-c '{}'",
+            self.program,
+            self.interactive_flag(),
+            command_label
+        )
     }
 
     /// Returns the program and arguments to run this task in a shell.
@@ -334,8 +368,10 @@ impl ShellBuilder {
                 command.push_str(&arg);
                 command
             });
-        self.args
-            .extend(["-i".to_owned(), "-c".to_owned(), combined_command]);
+        if self.interactive {
+            self.args.push("-i".to_owned());
+        }
+        self.args.extend(["-c".to_owned(), combined_command]);
 
         (self.program, self.args)
     }
@@ -357,7 +393,12 @@ impl ShellBuilder {
                 format!("{} /C '{}'", self.program, command_label)
             }
             WindowsShellType::Other => {
-                format!("{} -i -c '{}'", self.program, command_label)
+                format!(
+                    "{} {}-c '{}'",
+                    self.program,
+                    self.interactive_flag(),
+                    command_label
+                )
             }
         }
     }
@@ -376,8 +417,10 @@ impl ShellBuilder {
             WindowsShellType::Powershell => self.args.extend(["-C".to_owned(), combined_command]),
             WindowsShellType::Cmd => self.args.extend(["/C".to_owned(), combined_command]),
             WindowsShellType::Other => {
-                self.args
-                    .extend(["-i".to_owned(), "-c".to_owned(), combined_command])
+                if self.interactive {
+                    self.args.push("-i".to_owned());
+                }
+                self.args.extend(["-c".to_owned(), combined_command])
             }
         }
 
@@ -70,6 +70,47 @@ pub struct TaskTemplate {
     /// Whether to show the command line in the task output.
     #[serde(default = "default_true")]
     pub show_command: bool,
+    /// Whether to rerun the task if it finishes with a non-zero exit code, and how.
+    #[serde(default)]
+    pub retry: Option<TaskRetry>,
+    /// Whether to ask for a confirmation before spawning the task.
+    #[serde(default)]
+    pub confirm: bool,
+    /// Overrides to apply when the task runs on Windows.
+    #[serde(default)]
+    pub windows: Option<TaskOsOverrides>,
+    /// Overrides to apply when the task runs on Linux.
+    #[serde(default)]
+    pub linux: Option<TaskOsOverrides>,
+    /// Overrides to apply when the task runs on macOS.
+    #[serde(default)]
+    pub macos: Option<TaskOsOverrides>,
+}
+
+/// How to rerun a task that finished with a non-zero exit code.
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct TaskRetry {
+    /// How many more times to run the task after the first failed run.
+    pub attempts: u32,
+    /// How long to wait before each rerun, in milliseconds.
+    #[serde(default)]
+    pub delay_ms: u64,
+}
+
+/// Parts of a [`TaskTemplate`] that can be redefined for a particular OS.
+#[derive(Clone, Default, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct TaskOsOverrides {
+    /// Executable command to spawn instead of the template's one.
+    #[serde(default)]
+    pub command: Option<String>,
+    /// Arguments to use instead of the template's ones.
+    #[serde(default)]
+    pub args: Option<Vec<String>>,
+    /// Env overrides, applied on top of the template's `env`.
+    #[serde(default)]
+    pub env: HashMap<String, String>,
 }
 
 /// What to do with the terminal pane and tab, after the command was started.
@@ -122,7 +163,14 @@ impl TaskTemplate {
     /// Every [`ResolvedTask`] gets a [`TaskId`], based on the `id_base` (to avoid collision with various task sources),
     /// and hashes of its template and [`TaskContext`], see [`ResolvedTask`] fields' documentation for more details.
     pub fn resolve_task(&self, id_base: &str, cx: &TaskContext) -> Option<ResolvedTask> {
-        if self.label.trim().is_empty() || self.command.trim().is_empty() {
+        let os_overrides = self.os_overrides(cx.os.as_deref().unwrap_or(std::env::consts::OS));
+        let template_command = os_overrides
+            .and_then(|overrides| overrides.command.as_ref())
+            .unwrap_or(&self.command);
+        let template_args = os_overrides
+            .and_then(|overrides| overrides.args.as_ref())
+            .unwrap_or(&self.args);
+        if self.label.trim().is_empty() || template_command.trim().is_empty() {
             return None;
         }
 
@@ -186,13 +234,13 @@ impl TaskTemplate {
         });
 
         let command = substitute_all_template_variables_in_str(
-            &self.command,
+            template_command,
             &task_variables,
             &variable_names,
             &mut substituted_variables,
         )?;
         let args_with_substitutions = substitute_all_template_variables_in_vec(
-            &self.args,
+            template_args,
             &task_variables,
             &variable_names,
             &mut substituted_variables,
@@ -212,6 +260,9 @@ impl TaskTemplate {
 
             // Extend that environment with what's defined in the TaskTemplate
             env.extend(self.env.clone());
+            if let Some(os_overrides) = os_overrides {
+                env.extend(os_overrides.env.clone());
+            }
 
             // Then we replace all task variables that could be set in environment variables
             let mut env = substitute_all_template_variables_in_map(
@@ -245,7 +296,7 @@ impl TaskTemplate {
                     },
                 ),
                 command,
-                args: self.args.clone(),
+                args: template_args.clone(),
                 env,
                 use_new_terminal: self.use_new_terminal,
                 allow_concurrent_runs: self.allow_concurrent_runs,
@@ -255,9 +306,21 @@ impl TaskTemplate {
                 shell: self.shell.clone(),
                 show_summary: self.show_summary,
                 show_command: self.show_command,
+                retry: self.retry,
+                confirm: self.confirm,
             }),
         })
     }
+
+    /// Overrides declared for the given OS (as in [`std::env::consts::OS`]), if any.
+    fn os_overrides(&self, os: &str) -> Option<&TaskOsOverrides> {
+        match os {
+            "windows" => self.windows.as_ref(),
+            "macos" => self.macos.as_ref(),
+            "linux" => self.linux.as_ref(),
+            _ => None,
+        }
+    }
 }
 
 const MAX_DISPLAY_VARIABLE_LENGTH: usize = 15;
@@ -431,6 +494,7 @@ mod tests {
             cwd: None,
             task_variables: TaskVariables::default(),
             project_env: HashMap::default(),
+            os: None,
         };
         assert_eq!(
             resolved_task(&task_without_cwd, &cx).cwd,
@@ -443,6 +507,7 @@ mod tests {
             cwd: Some(context_cwd.clone()),
             task_variables: TaskVariables::default(),
             project_env: HashMap::default(),
+            os: None,
         };
         assert_eq!(
             resolved_task(&task_without_cwd, &cx).cwd,
@@ -459,6 +524,7 @@ mod tests {
             cwd: None,
             task_variables: TaskVariables::default(),
             project_env: HashMap::default(),
+            os: None,
         };
         assert_eq!(
             resolved_task(&task_with_cwd, &cx).cwd,
@@ -470,6 +536,7 @@ mod tests {
             cwd: Some(context_cwd.clone()),
             task_variables: TaskVariables::default(),
             project_env: HashMap::default(),
+            os: None,
         };
         assert_eq!(
             resolved_task(&task_with_cwd, &cx).cwd,
@@ -546,6 +613,7 @@ mod tests {
                     cwd: None,
                     task_variables: TaskVariables::from_iter(all_variables.clone()),
                     project_env: HashMap::default(),
+                    os: None,
                 },
             ).unwrap_or_else(|| panic!("Should successfully resolve task {task_with_all_variables:?} with variables {all_variables:?}"));
 
@@ -634,6 +702,7 @@ mod tests {
                     cwd: None,
                     task_variables: TaskVariables::from_iter(not_all_variables),
                     project_env: HashMap::default(),
+                    os: None,
                 },
             );
             assert_eq!(resolved_task_attempt, None, "If any of the Zed task variables is not substituted, the task should not be resolved, but got some resolution without the variable {removed_variable:?} (index {i})");
@@ -687,6 +756,7 @@ mod tests {
                 "test_symbol".to_string(),
             ))),
             project_env: HashMap::default(),
+            os: None,
         };
 
         for (i, symbol_dependent_task) in [
@@ -815,6 +885,7 @@ mod tests {
             cwd: None,
             task_variables: TaskVariables::from_iter(all_variables.clone()),
             project_env,
+            os: None,
         };
 
         let resolved = template
@@ -831,4 +902,61 @@ mod tests {
             "overwritten"
         );
     }
+
+    #[test]
+    fn test_os_overrides() {
+        let os_override = |command: &str| TaskOsOverrides {
+            command: Some(command.to_string()),
+            args: Some(vec![format!("{command}_arg")]),
+            env: HashMap::from_iter([("OS_ENV".to_string(), command.to_string())]),
+        };
+        let template = TaskTemplate {
+            label: "my task".to_string(),
+            command: "default_command".to_string(),
+            args: vec!["default_arg".to_string()],
+            env: HashMap::from_iter([
+                ("OS_ENV".to_string(), "default".to_string()),
+                ("TASK_ENV".to_string(), "task".to_string()),
+            ]),
+            windows: Some(os_override("windows_command")),
+            linux: Some(os_override("linux_command")),
+            macos: Some(os_override("macos_command")),
+            ..TaskTemplate::default()
+        };
+        let resolve = |os: Option<&str>| {
+            template
+                .resolve_task(
+                    TEST_ID_BASE,
+                    &TaskContext {
+                        os: os.map(ToString::to_string),
+                        ..TaskContext::default()
+                    },
+                )
+                .unwrap()
+                .resolved
+                .unwrap()
+        };
+
+        // The target OS is taken from the context, regardless of the OS the tests run on.
+        for os in ["windows", "linux", "macos"] {
+            let expected_command = format!("{os}_command");
+            let resolved = resolve(Some(os));
+            assert_eq!(resolved.command, expected_command);
+            assert_eq!(resolved.args, vec![format!("{expected_command}_arg")]);
+            assert_eq!(resolved.env["OS_ENV"], expected_command);
+            assert_eq!(resolved.env["TASK_ENV"], "task");
+            assert_eq!(
+                resolved.command_label,
+                format!("{expected_command} {expected_command}_arg")
+            );
+        }
+
+        let resolved = resolve(Some("freebsd"));
+        assert_eq!(resolved.command, "default_command");
+        assert_eq!(resolved.args, vec!["default_arg".to_string()]);
+        assert_eq!(resolved.env["OS_ENV"], "default");
+
+        let host_resolved = resolve(None);
+        assert_eq!(host_resolved, resolve(Some(std::env::consts::OS)));
+    }
 }
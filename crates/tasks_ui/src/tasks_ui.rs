@@ -47,13 +47,14 @@ pub fn init(cx: &mut App) {
                             cx.spawn_in(window, |workspace, mut cx| async move {
                                 let task_context = context_task.await;
                                 workspace
-                                    .update(&mut cx, |workspace, cx| {
+                                    .update_in(&mut cx, |workspace, window, cx| {
                                         schedule_task(
                                             workspace,
                                             task_source_kind,
                                             &original_task,
                                             &task_context,
                                             false,
+                                            window,
                                             cx,
                                         )
                                     })
@@ -75,6 +76,7 @@ pub fn init(cx: &mut App) {
                                 task_source_kind,
                                 last_scheduled_task,
                                 false,
+                                window,
                                 cx,
                             );
                         }
@@ -184,7 +186,7 @@ fn spawn_task_with_name(
         })?;
 
         let did_spawn = workspace
-            .update(&mut cx, |workspace, cx| {
+            .update_in(&mut cx, |workspace, window, cx| {
                 let (task_source_kind, mut target_task) =
                     tasks.into_iter().find(|(_, task)| task.label == name)?;
                 if let Some(overrides) = &overrides {
@@ -198,6 +200,7 @@ fn spawn_task_with_name(
                     &target_task,
                     &task_context,
                     false,
+                    window,
                     cx,
                 );
                 Some(())
@@ -390,6 +393,7 @@ mod tests {
                     (VariableName::Column, "1".into()),
                 ]),
                 project_env: HashMap::default(),
+                os: Some(std::env::consts::OS.to_string()),
             }
         );
 
@@ -421,6 +425,7 @@ mod tests {
                     (VariableName::Symbol, "this_is_a_rust_file".into()),
                 ]),
                 project_env: HashMap::default(),
+                os: Some(std::env::consts::OS.to_string()),
             }
         );
 
@@ -446,6 +451,7 @@ mod tests {
                     (VariableName::Symbol, "this_is_a_test".into()),
                 ]),
                 project_env: HashMap::default(),
+                os: Some(std::env::consts::OS.to_string()),
             }
         );
     }
@@ -645,6 +645,35 @@ pub struct TaskState {
     pub hide: HideStrategy,
     pub show_summary: bool,
     pub show_command: bool,
+    /// Whether the user interrupted the task (e.g. with Ctrl-C) while it was running.
+    pub interrupted: bool,
+    /// How many more times the task gets rerun in place of this terminal if it fails.
+    pub retries_left: u32,
+}
+
+impl TaskState {
+    /// Whether the task failed and is going to be rerun in place of this terminal.
+    pub fn will_retry(&self) -> bool {
+        self.retries_left > 0
+            && !self.interrupted
+            && self.status == TaskStatus::Completed { success: false }
+    }
+
+    fn register_input(&mut self, input: &[u8]) {
+        const ETX: u8 = 0x03;
+        if self.status == TaskStatus::Running && input.contains(&ETX) {
+            self.interrupted = true;
+        }
+    }
+
+    fn should_hide(&self, finished_successfully: bool) -> bool {
+        match self.hide {
+            HideStrategy::Never => false,
+            // Keep the terminal around for the rerun to replace.
+            HideStrategy::Always => !self.will_retry(),
+            HideStrategy::OnSuccess => finished_successfully,
+        }
+    }
 }
 
 /// A status of the current terminal tab's task.
@@ -1155,6 +1184,7 @@ impl Terminal {
             .push_back(InternalEvent::Scroll(AlacScroll::Bottom));
         self.events.push_back(InternalEvent::SetSelection(None));
 
+        self.register_task_interrupt(input.as_bytes());
         self.write_to_pty(input);
     }
 
@@ -1163,9 +1193,16 @@ impl Terminal {
             .push_back(InternalEvent::Scroll(AlacScroll::Bottom));
         self.events.push_back(InternalEvent::SetSelection(None));
 
+        self.register_task_interrupt(&input);
         self.write_bytes_to_pty(input);
     }
 
+    fn register_task_interrupt(&mut self, input: &[u8]) {
+        if let Some(task) = &mut self.task {
+            task.register_input(input);
+        }
+    }
+
     pub fn toggle_vi_mode(&mut self) {
         self.events.push_back(InternalEvent::ToggleViMode);
     }
@@ -1775,16 +1812,8 @@ impl Terminal {
             unsafe { append_text_to_term(&mut self.term.lock(), &lines_to_show) };
         }
 
-        match task.hide {
-            HideStrategy::Never => {}
-            HideStrategy::Always => {
-                cx.emit(Event::CloseTerminal);
-            }
-            HideStrategy::OnSuccess => {
-                if finished_successfully {
-                    cx.emit(Event::CloseTerminal);
-                }
-            }
+        if task.should_hide(finished_successfully) {
+            cx.emit(Event::CloseTerminal);
         }
     }
 }
@@ -2002,8 +2031,10 @@ mod tests {
     use rand::{distributions::Alphanumeric, rngs::ThreadRng, thread_rng, Rng};
 
     use crate::{
-        content_index_for_mouse, rgb_for_index, IndexedCell, TerminalContent, TerminalSize,
+        content_index_for_mouse, rgb_for_index, IndexedCell, TaskState, TaskStatus,
+        TerminalContent, TerminalSize,
     };
+    use task::{HideStrategy, TaskId};
 
     #[test]
     fn test_rgb_for_index() {
@@ -2180,4 +2211,64 @@ mod tests {
             vec!["Main.cs:20:5:Error", "desc"],
         );
     }
+
+    fn test_task_state(hide: HideStrategy, retries_left: u32) -> TaskState {
+        TaskState {
+            id: TaskId("test".to_string()),
+            full_label: "test".to_string(),
+            label: "test".to_string(),
+            command_label: "test".to_string(),
+            status: TaskStatus::Running,
+            completion_rx: smol::channel::bounded(1).1,
+            hide,
+            show_summary: true,
+            show_command: true,
+            interrupted: false,
+            retries_left,
+        }
+    }
+
+    #[test]
+    fn test_task_interrupt_registered_only_while_running() {
+        let mut task = test_task_state(HideStrategy::Never, 1);
+        task.register_input(b"echo hello\r");
+        assert!(!task.interrupted);
+
+        task.status = TaskStatus::Completed { success: false };
+        task.register_input(b"\x03");
+        assert!(!task.interrupted, "input after the task ended is ignored");
+        assert!(task.will_retry());
+
+        task.status = TaskStatus::Running;
+        task.register_input("partial input\x03".as_bytes());
+        assert!(task.interrupted);
+        task.status = TaskStatus::Completed { success: false };
+        assert!(!task.will_retry(), "interrupted runs are not retried");
+    }
+
+    #[test]
+    fn test_failed_task_with_retries_left_is_not_hidden() {
+        let mut task = test_task_state(HideStrategy::Always, 1);
+        task.status = TaskStatus::Completed { success: false };
+        assert!(
+            !task.should_hide(false),
+            "the terminal is kept for the rerun to replace"
+        );
+
+        task.status = TaskStatus::Completed { success: true };
+        assert!(task.should_hide(true));
+
+        let mut last_attempt = test_task_state(HideStrategy::Always, 0);
+        last_attempt.status = TaskStatus::Completed { success: false };
+        assert!(last_attempt.should_hide(false));
+
+        let mut interrupted = test_task_state(HideStrategy::Always, 1);
+        interrupted.register_input(b"\x03");
+        interrupted.status = TaskStatus::Completed { success: false };
+        assert!(interrupted.should_hide(false));
+
+        let mut on_success = test_task_state(HideStrategy::OnSuccess, 1);
+        on_success.status = TaskStatus::Completed { success: false };
+        assert!(!on_success.should_hide(false));
+    }
 }
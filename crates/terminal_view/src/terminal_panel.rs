@@ -10,7 +10,7 @@ use crate::{
 use breadcrumbs::Breadcrumbs;
 use collections::HashMap;
 use db::kvp::KEY_VALUE_STORE;
-use futures::future::join_all;
+use futures::{future::join_all, Future};
 use gpui::{
     actions, Action, AnyView, App, AsyncApp, AsyncWindowContext, Context, Corner, Entity,
     EventEmitter, ExternalPaths, FocusHandle, Focusable, IntoElement, ParentElement, Pixels,
@@ -20,7 +20,7 @@ use itertools::Itertools;
 use project::{terminals::TerminalKind, Fs, Project, ProjectEntryId};
 use search::{buffer_search::DivRegistrar, BufferSearchBar};
 use settings::Settings;
-use task::{RevealStrategy, RevealTarget, ShellBuilder, SpawnInTerminal, TaskId};
+use task::{RevealStrategy, RevealTarget, ShellBuilder, SpawnInTerminal, TaskId, TaskRetry};
 use terminal::{
     terminal_settings::{TerminalDockPosition, TerminalSettings},
     Terminal,
@@ -73,6 +73,7 @@ pub struct TerminalPanel {
     pending_serialization: Task<Option<()>>,
     pending_terminals_to_add: usize,
     deferred_tasks: HashMap<TaskId, Task<()>>,
+    pending_retries: HashMap<TaskId, Task<Option<()>>>,
     assistant_enabled: bool,
     assistant_tab_bar_button: Option<AnyView>,
     active: bool,
@@ -93,6 +94,7 @@ impl TerminalPanel {
             height: None,
             pending_terminals_to_add: 0,
             deferred_tasks: HashMap::default(),
+            pending_retries: HashMap::default(),
             assistant_enabled: false,
             assistant_tab_bar_button: None,
             active: false,
@@ -457,48 +459,47 @@ impl TerminalPanel {
     }
 
     fn spawn_task(&mut self, task: &SpawnInTerminal, window: &mut Window, cx: &mut Context<Self>) {
-        let Ok(is_local) = self
-            .workspace
-            .update(cx, |workspace, cx| workspace.project().read(cx).is_local())
-        else {
+        // A new run of the task supersedes the retries left over from its previous run.
+        self.pending_retries.remove(&task.id);
+        let original_task = task.clone();
+        let Some(task) = self.wrap_in_shell(task, cx) else {
             return;
         };
 
-        let builder = ShellBuilder::new(is_local, &task.shell);
-        let command_label = builder.command_label(&task.command_label);
-        let (command, args) = builder.build(task.command.clone(), &task.args);
-
-        let task = SpawnInTerminal {
-            command_label,
-            command,
-            args,
-            ..task.clone()
-        };
-
         if task.allow_concurrent_runs && task.use_new_terminal {
-            self.spawn_in_new_terminal(task, window, cx)
-                .detach_and_log_err(cx);
+            let new_terminal = self.spawn_in_new_terminal(task, window, cx);
+            self.retry_on_failure(
+                original_task,
+                async move { new_terminal.await.log_err() },
+                window,
+                cx,
+            );
             return;
         }
 
         let mut terminals_for_task = self.terminals_for_task(&task.full_label, cx);
         let Some(existing) = terminals_for_task.pop() else {
-            self.spawn_in_new_terminal(task, window, cx)
-                .detach_and_log_err(cx);
+            let new_terminal = self.spawn_in_new_terminal(task, window, cx);
+            self.retry_on_failure(
+                original_task,
+                async move { new_terminal.await.log_err() },
+                window,
+                cx,
+            );
             return;
         };
 
         let (existing_item_index, task_pane, existing_terminal) = existing;
         if task.allow_concurrent_runs {
-            self.replace_terminal(
+            let replaced_terminal = self.replace_terminal(
                 task,
                 task_pane,
                 existing_item_index,
                 existing_terminal,
                 window,
                 cx,
-            )
-            .detach();
+            );
+            self.retry_on_failure(original_task, replaced_terminal, window, cx);
             return;
         }
 
@@ -506,30 +507,94 @@ impl TerminalPanel {
             task.id.clone(),
             cx.spawn_in(window, |terminal_panel, mut cx| async move {
                 wait_for_terminals_tasks(terminals_for_task, &mut cx).await;
-                let task = terminal_panel.update_in(&mut cx, |terminal_panel, window, cx| {
-                    if task.use_new_terminal {
-                        terminal_panel
-                            .spawn_in_new_terminal(task, window, cx)
-                            .detach_and_log_err(cx);
-                        None
-                    } else {
-                        Some(terminal_panel.replace_terminal(
-                            task,
-                            task_pane,
-                            existing_item_index,
-                            existing_terminal,
-                            window,
-                            cx,
-                        ))
-                    }
-                });
-                if let Ok(Some(task)) = task {
-                    task.await;
-                }
+                terminal_panel
+                    .update_in(&mut cx, |terminal_panel, window, cx| {
+                        if task.use_new_terminal {
+                            let new_terminal =
+                                terminal_panel.spawn_in_new_terminal(task, window, cx);
+                            terminal_panel.retry_on_failure(
+                                original_task,
+                                async move { new_terminal.await.log_err() },
+                                window,
+                                cx,
+                            );
+                        } else {
+                            let replaced_terminal = terminal_panel.replace_terminal(
+                                task,
+                                task_pane,
+                                existing_item_index,
+                                existing_terminal,
+                                window,
+                                cx,
+                            );
+                            terminal_panel.retry_on_failure(
+                                original_task,
+                                replaced_terminal,
+                                window,
+                                cx,
+                            );
+                        }
+                    })
+                    .ok();
             }),
         );
     }
 
+    fn wrap_in_shell(
+        &self,
+        task: &SpawnInTerminal,
+        cx: &mut Context<Self>,
+    ) -> Option<SpawnInTerminal> {
+        let is_local = self
+            .workspace
+            .update(cx, |workspace, cx| workspace.project().read(cx).is_local())
+            .ok()?;
+        let builder = ShellBuilder::new(is_local, &task.shell);
+        let command_label = builder.command_label(&task.command_label);
+        let (command, args) = builder.build(task.command.clone(), &task.args);
+
+        Some(SpawnInTerminal {
+            command_label,
+            command,
+            args,
+            ..task.clone()
+        })
+    }
+
+    /// Once the task's terminal is spawned, watches it and reruns the task in that same
+    /// terminal if the run fails, for as long as the task has retry attempts left.
+    fn retry_on_failure(
+        &self,
+        task: SpawnInTerminal,
+        spawned_terminal: impl Future<Output = Option<Entity<Terminal>>> + 'static,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        cx.spawn_in(window, |terminal_panel, mut cx| async move {
+            let terminal = spawned_terminal.await?.downgrade();
+            task.retry.filter(|retry| retry.attempts > 0)?;
+            terminal_panel
+                .update_in(&mut cx, |terminal_panel, window, cx| {
+                    let task_id = task.id.clone();
+                    let retry = cx.spawn_in(window, |terminal_panel, mut cx| async move {
+                        let task_id = task.id.clone();
+                        let retried =
+                            retry_failed_task(terminal_panel.clone(), task, terminal, &mut cx)
+                                .await;
+                        terminal_panel
+                            .update(&mut cx, |terminal_panel, _| {
+                                terminal_panel.pending_retries.remove(&task_id);
+                            })
+                            .ok();
+                        retried
+                    });
+                    terminal_panel.pending_retries.insert(task_id, retry);
+                })
+                .ok()
+        })
+        .detach();
+    }
+
     pub fn spawn_in_new_terminal(
         &mut self,
         spawn_task: SpawnInTerminal,
@@ -773,7 +838,7 @@ impl TerminalPanel {
         terminal_to_replace: Entity<TerminalView>,
         window: &mut Window,
         cx: &mut Context<Self>,
-    ) -> Task<Option<()>> {
+    ) -> Task<Option<Entity<Terminal>>> {
         let reveal = spawn_task.reveal;
         let reveal_target = spawn_task.reveal_target;
         let window_handle = window.window_handle();
@@ -796,7 +861,7 @@ impl TerminalPanel {
                 .log_err()?;
             terminal_to_replace
                 .update_in(&mut cx, |terminal_to_replace, window, cx| {
-                    terminal_to_replace.set_terminal(new_terminal, window, cx);
+                    terminal_to_replace.set_terminal(new_terminal.clone(), window, cx);
                 })
                 .ok()?;
 
@@ -872,7 +937,7 @@ impl TerminalPanel {
                 RevealStrategy::Never => {}
             }
 
-            Some(())
+            Some(new_terminal)
         })
     }
 
@@ -1080,6 +1145,62 @@ async fn wait_for_terminals_tasks(
     let _: Vec<()> = join_all(pending_tasks).await;
 }
 
+/// Reruns the task in place of its terminal if the run finished with a non-zero exit code.
+///
+/// Does nothing if the user interrupted the run, or if the terminal was closed in the meantime.
+async fn retry_failed_task(
+    terminal_panel: WeakEntity<TerminalPanel>,
+    task: SpawnInTerminal,
+    terminal: WeakEntity<Terminal>,
+    cx: &mut AsyncWindowContext,
+) -> Option<()> {
+    let retry = task.retry.filter(|retry| retry.attempts > 0)?;
+    terminal
+        .update(cx, |terminal, cx| terminal.wait_for_completed_task(cx))
+        .ok()?
+        .await;
+    let will_retry = terminal
+        .read_with(cx, |terminal, _| {
+            terminal.task().map_or(false, |task| task.will_retry())
+        })
+        .ok()?;
+    if !will_retry {
+        return None;
+    }
+
+    cx.background_executor()
+        .timer(Duration::from_millis(retry.delay_ms))
+        .await;
+    terminal_panel
+        .update_in(cx, |terminal_panel, window, cx| {
+            let (item_index, task_pane, terminal_view) = terminal_panel
+                .terminals_for_task(&task.full_label, cx)
+                .into_iter()
+                .find(|(_, _, terminal_view)| {
+                    terminal_view.read(cx).terminal().entity_id() == terminal.entity_id()
+                })?;
+            let task = SpawnInTerminal {
+                retry: Some(TaskRetry {
+                    attempts: retry.attempts - 1,
+                    ..retry
+                }),
+                ..task
+            };
+            let shell_task = terminal_panel.wrap_in_shell(&task, cx)?;
+            let replaced_terminal = terminal_panel.replace_terminal(
+                shell_task,
+                task_pane,
+                item_index,
+                terminal_view,
+                window,
+                cx,
+            );
+            terminal_panel.retry_on_failure(task, replaced_terminal, window, cx);
+            Some(())
+        })
+        .ok()?
+}
+
 fn add_paths_to_terminal(
     pane: &mut Pane,
     paths: &[PathBuf],
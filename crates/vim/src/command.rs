@@ -1304,6 +1304,8 @@ impl ShellExec {
                         shell,
                         show_summary: false,
                         show_command: false,
+                        retry: None,
+                        confirm: false,
                     }),
                 });
             });
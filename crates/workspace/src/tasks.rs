@@ -1,7 +1,10 @@
-use gpui::Context;
+use std::{process::Stdio, time::Duration};
+
+use anyhow::{anyhow, Context as _};
+use gpui::{App, Context, PromptLevel, Task, Window};
 use project::TaskSourceKind;
 use remote::ConnectionState;
-use task::{ResolvedTask, TaskContext, TaskTemplate};
+use task::{ResolvedTask, ShellBuilder, SpawnInTerminal, TaskContext, TaskId, TaskTemplate};
 
 use crate::Workspace;
 
@@ -11,6 +14,7 @@ pub fn schedule_task(
     task_to_resolve: &TaskTemplate,
     task_cx: &TaskContext,
     omit_history: bool,
+    window: &mut Window,
     cx: &mut Context<Workspace>,
 ) {
     match workspace.project.read(cx).ssh_connection_state(cx) {
@@ -34,6 +38,7 @@ pub fn schedule_task(
             task_source_kind,
             spawn_in_terminal,
             omit_history,
+            window,
             cx,
         );
     }
@@ -44,24 +49,385 @@ pub fn schedule_resolved_task(
     task_source_kind: TaskSourceKind,
     mut resolved_task: ResolvedTask,
     omit_history: bool,
+    window: &mut Window,
     cx: &mut Context<Workspace>,
 ) {
-    if let Some(spawn_in_terminal) = resolved_task.resolved.take() {
-        if !omit_history {
-            resolved_task.resolved = Some(spawn_in_terminal.clone());
-            workspace.project().update(cx, |project, cx| {
-                if let Some(task_inventory) =
-                    project.task_store().read(cx).task_inventory().cloned()
-                {
-                    task_inventory.update(cx, |inventory, _| {
-                        inventory.task_scheduled(task_source_kind, resolved_task);
-                    })
+    let Some(spawn_in_terminal) = resolved_task.resolved.take() else {
+        return;
+    };
+    if !spawn_in_terminal.confirm {
+        spawn_resolved_task(
+            workspace,
+            task_source_kind,
+            resolved_task,
+            spawn_in_terminal,
+            omit_history,
+            cx,
+        );
+        return;
+    }
+
+    let confirmation = confirm_task(&spawn_in_terminal, window, cx);
+    cx.spawn_in(window, |workspace, mut cx| async move {
+        if confirmation.await {
+            workspace.update(&mut cx, |workspace, cx| {
+                spawn_resolved_task(
+                    workspace,
+                    task_source_kind,
+                    resolved_task,
+                    spawn_in_terminal,
+                    omit_history,
+                    cx,
+                )
+            })?;
+        }
+        anyhow::Ok(())
+    })
+    .detach_and_log_err(cx);
+}
+
+fn spawn_resolved_task(
+    workspace: &mut Workspace,
+    task_source_kind: TaskSourceKind,
+    resolved_task: ResolvedTask,
+    spawn_in_terminal: SpawnInTerminal,
+    omit_history: bool,
+    cx: &mut Context<Workspace>,
+) {
+    if !omit_history {
+        record_task_in_history(
+            workspace,
+            task_source_kind,
+            resolved_task,
+            &spawn_in_terminal,
+            cx,
+        );
+    }
+
+    cx.emit(crate::Event::SpawnTask {
+        action: Box::new(spawn_in_terminal),
+    });
+}
+
+/// Asks the user whether to run a task that has `confirm: true` set.
+fn confirm_task(
+    spawn_in_terminal: &SpawnInTerminal,
+    window: &mut Window,
+    cx: &mut App,
+) -> Task<bool> {
+    let answer = window.prompt(
+        PromptLevel::Warning,
+        &format!("Run task `{}`?", spawn_in_terminal.full_label),
+        Some(&spawn_in_terminal.command_label),
+        &["Run", "Cancel"],
+        cx,
+    );
+    cx.foreground_executor()
+        .spawn(async move { answer.await == Ok(0) })
+}
+
+/// Outcome of a task that was run without a terminal, see [`run_headless_task`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeadlessTaskRun {
+    /// Id of the task that was run.
+    pub id: TaskId,
+    /// Exit code of the task's process, `None` if it was terminated by a signal.
+    pub exit_code: Option<i32>,
+    /// Everything the task wrote into its stdout.
+    pub stdout: String,
+    /// Everything the task wrote into its stderr.
+    pub stderr: String,
+}
+
+impl HeadlessTaskRun {
+    /// Whether the task's process exited with code 0.
+    pub fn succeeded(&self) -> bool {
+        self.exit_code == Some(0)
+    }
+}
+
+/// Runs the resolved task in the background, without opening a terminal tab for it,
+/// and captures its exit code and output.
+///
+/// The task's `confirm` and `retry` settings apply as for tasks spawned in a terminal;
+/// when the task is retried, the last run is returned.
+/// Headless runs are not added to the task history, so rerunning a task never turns
+/// a headless run into a terminal one. Instead, the last run of each task is kept
+/// around, see [`Workspace::last_headless_task_run`].
+pub fn run_headless_task(
+    workspace: &mut Workspace,
+    mut resolved_task: ResolvedTask,
+    window: &mut Window,
+    cx: &mut Context<Workspace>,
+) -> Task<anyhow::Result<HeadlessTaskRun>> {
+    if !workspace.project.read(cx).is_local() {
+        return Task::ready(Err(anyhow!(
+            "headless tasks are not supported for remote projects"
+        )));
+    }
+    let Some(spawn_in_terminal) = resolved_task.resolved.take() else {
+        return Task::ready(Err(anyhow!(
+            "task `{}` has nothing to run",
+            resolved_task.resolved_label
+        )));
+    };
+
+    let confirmation = spawn_in_terminal
+        .confirm
+        .then(|| confirm_task(&spawn_in_terminal, window, cx));
+    cx.spawn_in(window, |workspace, mut cx| async move {
+        if let Some(confirmation) = confirmation {
+            if !confirmation.await {
+                anyhow::bail!("task `{}` was not confirmed", spawn_in_terminal.full_label);
+            }
+        }
+        let project_directory = workspace.update(&mut cx, |workspace, cx| {
+            workspace.project().read(cx).active_project_directory(cx)
+        })?;
+
+        let SpawnInTerminal {
+            id,
+            full_label,
+            command,
+            args,
+            cwd,
+            env,
+            shell,
+            retry,
+            ..
+        } = spawn_in_terminal;
+        let (program, args) = ShellBuilder::new(true, &shell)
+            .non_interactive()
+            .build(command, &args);
+        let mut command = util::command::new_smol_command(program);
+        command
+            .args(args)
+            .envs(env)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true);
+        // Same as for terminal tasks, run in the project directory unless told otherwise.
+        if let Some(cwd) = cwd.or_else(|| project_directory.map(|dir| dir.to_path_buf())) {
+            command.current_dir(cwd);
+        }
+
+        let executor = cx.background_executor().clone();
+        let mut retry = retry.unwrap_or_default();
+        let run = cx
+            .background_executor()
+            .spawn(async move {
+                loop {
+                    let output = command
+                        .output()
+                        .await
+                        .with_context(|| format!("running task `{full_label}`"))?;
+                    let run = HeadlessTaskRun {
+                        id: id.clone(),
+                        exit_code: output.status.code(),
+                        stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+                        stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+                    };
+                    if run.succeeded() || retry.attempts == 0 {
+                        return Ok(run);
+                    }
+
+                    retry.attempts -= 1;
+                    executor.timer(Duration::from_millis(retry.delay_ms)).await;
                 }
-            });
+            })
+            .await?;
+        workspace.update(&mut cx, |workspace, _| {
+            workspace
+                .headless_task_runs
+                .insert(run.id.clone(), run.clone());
+        })?;
+        Ok(run)
+    })
+}
+
+impl Workspace {
+    /// Returns the outcome of the last headless run of the given task, see [`run_headless_task`].
+    pub fn last_headless_task_run(&self, task_id: &TaskId) -> Option<&HeadlessTaskRun> {
+        self.headless_task_runs.get(task_id)
+    }
+}
+
+fn record_task_in_history(
+    workspace: &mut Workspace,
+    task_source_kind: TaskSourceKind,
+    mut resolved_task: ResolvedTask,
+    spawn_in_terminal: &SpawnInTerminal,
+    cx: &mut Context<Workspace>,
+) {
+    resolved_task.resolved = Some(spawn_in_terminal.clone());
+    workspace.project().update(cx, |project, cx| {
+        if let Some(task_inventory) = project.task_store().read(cx).task_inventory().cloned() {
+            task_inventory.update(cx, |inventory, _| {
+                inventory.task_scheduled(task_source_kind, resolved_task);
+            })
         }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use fs::FakeFs;
+    use gpui::{TestAppContext, VisualTestContext};
+    use project::Project;
 
-        cx.emit(crate::Event::SpawnTask {
-            action: Box::new(spawn_in_terminal),
+    use super::*;
+    use crate::tests::init_test;
+
+    #[gpui::test]
+    async fn test_declined_task_is_not_scheduled(cx: &mut TestAppContext) {
+        init_test(cx);
+        let fs = FakeFs::new(cx.executor());
+        let project = Project::test(fs, [], cx).await;
+        let (workspace, cx) =
+            cx.add_window_view(|window, cx| Workspace::test_new(project.clone(), window, cx));
+
+        let spawned_tasks = Rc::new(RefCell::new(Vec::new()));
+        cx.update(|_, cx| {
+            let spawned_tasks = spawned_tasks.clone();
+            cx.subscribe(&workspace, move |_, event: &crate::Event, _| {
+                if let crate::Event::SpawnTask { action } = event {
+                    spawned_tasks.borrow_mut().push(action.label.clone());
+                }
+            })
+            .detach();
+        });
+        let has_history = |cx: &mut VisualTestContext| {
+            project.read_with(cx, |project, cx| {
+                project
+                    .task_store()
+                    .read(cx)
+                    .task_inventory()
+                    .unwrap()
+                    .read(cx)
+                    .last_scheduled_task(None)
+                    .is_some()
+            })
+        };
+
+        let task = TaskTemplate {
+            label: "deploy".to_string(),
+            command: "deploy".to_string(),
+            confirm: true,
+            ..TaskTemplate::default()
+        };
+        let resolved_task = task.resolve_task("test", &TaskContext::default()).unwrap();
+
+        workspace.update_in(cx, |workspace, window, cx| {
+            schedule_resolved_task(
+                workspace,
+                TaskSourceKind::UserInput,
+                resolved_task.clone(),
+                false,
+                window,
+                cx,
+            )
+        });
+        cx.run_until_parked();
+        assert!(cx.has_pending_prompt());
+        cx.simulate_prompt_answer(1);
+        cx.run_until_parked();
+        assert!(spawned_tasks.borrow().is_empty());
+        assert!(!has_history(cx));
+
+        workspace.update_in(cx, |workspace, window, cx| {
+            schedule_resolved_task(
+                workspace,
+                TaskSourceKind::UserInput,
+                resolved_task,
+                false,
+                window,
+                cx,
+            )
+        });
+        cx.run_until_parked();
+        cx.simulate_prompt_answer(0);
+        cx.run_until_parked();
+        assert_eq!(*spawned_tasks.borrow(), vec!["deploy".to_string()]);
+        assert!(has_history(cx));
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[gpui::test]
+    async fn test_run_headless_task(cx: &mut TestAppContext) {
+        init_test(cx);
+        cx.executor().allow_parking();
+        let project_dir = tempfile::tempdir().unwrap();
+        let project_path = project_dir.path().canonicalize().unwrap();
+        let fs = FakeFs::new(cx.executor());
+        fs.insert_tree(&project_path, serde_json::json!({})).await;
+        let project = Project::test(fs, [project_path.as_path()], cx).await;
+        let (workspace, cx) =
+            cx.add_window_view(|window, cx| Workspace::test_new(project.clone(), window, cx));
+
+        let failing_task = TaskTemplate {
+            label: "failing".to_string(),
+            command: "echo out; echo err >&2; exit 3".to_string(),
+            shell: task::Shell::Program("sh".to_string()),
+            ..TaskTemplate::default()
+        };
+        let run = run_template(&workspace, &failing_task, cx).await.unwrap();
+        assert_eq!(run.exit_code, Some(3));
+        assert_eq!(run.stdout, "out\n");
+        assert_eq!(run.stderr, "err\n");
+        assert!(!run.succeeded());
+        workspace.update(cx, |workspace, _| {
+            assert_eq!(workspace.last_headless_task_run(&run.id), Some(&run));
         });
+        project.read_with(cx, |project, cx| {
+            let inventory = project.task_store().read(cx).task_inventory().unwrap();
+            assert!(
+                inventory.read(cx).last_scheduled_task(None).is_none(),
+                "headless runs are not added to the task history"
+            );
+        });
+
+        let pwd_task = TaskTemplate {
+            label: "pwd".to_string(),
+            command: "pwd -P".to_string(),
+            shell: task::Shell::Program("sh".to_string()),
+            ..TaskTemplate::default()
+        };
+        let run = run_template(&workspace, &pwd_task, cx).await.unwrap();
+        assert_eq!(
+            run.stdout.trim_end(),
+            project_path.to_string_lossy(),
+            "tasks without a cwd run in the project directory"
+        );
+
+        let flaky_task = TaskTemplate {
+            label: "flaky".to_string(),
+            command: "if [ -f marker ]; then echo done; else touch marker; exit 1; fi".to_string(),
+            shell: task::Shell::Program("sh".to_string()),
+            retry: Some(task::TaskRetry {
+                attempts: 1,
+                delay_ms: 0,
+            }),
+            ..TaskTemplate::default()
+        };
+        let run = run_template(&workspace, &flaky_task, cx).await.unwrap();
+        assert!(run.succeeded());
+        assert_eq!(run.stdout, "done\n");
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    fn run_template(
+        workspace: &gpui::Entity<Workspace>,
+        template: &TaskTemplate,
+        cx: &mut VisualTestContext,
+    ) -> Task<anyhow::Result<HeadlessTaskRun>> {
+        let resolved_task = template
+            .resolve_task("test", &TaskContext::default())
+            .unwrap();
+        workspace.update_in(cx, |workspace, window, cx| {
+            run_headless_task(workspace, resolved_task, window, cx)
+        })
     }
 }